@@ -1,4 +1,5 @@
 use std::collections::{HashMap, HashSet};
+use std::fmt;
 
 use crate::adlgen::sys::adlast2 as adlast;
 use crate::adlrt::custom::sys::types::map::Map;
@@ -9,25 +10,122 @@ use super::{Module0, TypeExpr0};
 
 type Result<T> = std::result::Result<T, ResolveError>;
 
+/// Source span threaded through the AST by the parser (`nom_locate::LocatedSpan`).
+pub type Span = adlast::Span;
+
 #[derive(Debug)]
 pub enum ResolveError {
     NoDeclForAnnotation,
     ModuleNotFound,
-    DeclNotFound,
-    LocalNotFound(String),
-    CircularModules(ModuleName),
+    DeclNotFound { name: String, suggestion: Option<String> },
+    LocalNotFound { name: String, suggestion: Option<String> },
+    AmbiguousName { name: String, candidates: Vec<adlast::ScopedName> },
+    CircularModules(Vec<ModuleName>),
     LoadFailed,
 }
 
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ResolveError::NoDeclForAnnotation => write!(f, "annotation does not refer to a declaration"),
+            ResolveError::ModuleNotFound => write!(f, "module not found"),
+            ResolveError::DeclNotFound { name, suggestion } => {
+                write!(f, "no declaration `{}`", name)?;
+                write_suggestion(f, suggestion)
+            }
+            ResolveError::LocalNotFound { name, suggestion } => {
+                write!(f, "unresolved name `{}`", name)?;
+                write_suggestion(f, suggestion)
+            }
+            ResolveError::AmbiguousName { name, candidates } => {
+                let modules: Vec<&str> = candidates.iter().map(|sn| sn.module_name.as_str()).collect();
+                write!(f, "ambiguous name `{}`, exported by {}", name, modules.join(", "))
+            }
+            ResolveError::CircularModules(cycle) => {
+                write!(f, "circular module dependency: {}", cycle.join(" -> "))
+            }
+            ResolveError::LoadFailed => write!(f, "module failed to load"),
+        }
+    }
+}
+
+fn write_suggestion(f: &mut fmt::Formatter, suggestion: &Option<String>) -> fmt::Result {
+    if let Some(s) = suggestion {
+        write!(f, "; did you mean `{}`?", s)?;
+    }
+    Ok(())
+}
+
+/// Severity of a [`ResolveDiagnostic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// A single resolution problem, carrying a source span so downstream tooling can render a
+/// `line:col` location. The span is that of the enclosing decl, field or module name, not of
+/// the offending reference itself (see [`resolve_type_expr`]). Unlike [`ResolveError`],
+/// diagnostics are accumulated rather than aborting on the first failure.
+#[derive(Debug, Clone)]
+pub struct ResolveDiagnostic {
+    pub severity: Severity,
+    pub span: Span,
+    pub message: String,
+}
+
+impl ResolveDiagnostic {
+    fn error(span: Span, err: &ResolveError) -> Self {
+        ResolveDiagnostic {
+            severity: Severity::Error,
+            span,
+            message: err.to_string(),
+        }
+    }
+
+    fn warning(span: Span, message: String) -> Self {
+        ResolveDiagnostic {
+            severity: Severity::Warning,
+            span,
+            message,
+        }
+    }
+}
+
+impl fmt::Display for ResolveDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.severity, self.message)
+    }
+}
+
 pub type TypeRef = adlast::TypeRef;
 pub type TypeExpr1 = adlast::TypeExpr1;
 pub type Decl1 = adlast::Decl1;
 pub type Module1 = adlast::Module1;
 pub type ModuleName = adlast::ModuleName;
 
+/// Wildcard imports injected into every module by [`Resolver::add_default_imports`]. These
+/// are not written by the author, so the unused-import lint must not flag them.
+const DEFAULT_IMPORTS: &[&str] = &["sys.annotations"];
+
 pub struct Resolver {
     loader: Box<dyn AdlLoader>,
     modules: HashMap<ModuleName, Module1>,
+    diagnostics: HashMap<ModuleName, Vec<ResolveDiagnostic>>,
+    // Dependency edges discovered by `find_module_refs`: `forward_deps[m]` is the set of
+    // modules `m` imports, and `reverse_deps[m]` is the set of modules that import `m`.
+    // Together they drive demand-driven invalidation.
+    forward_deps: HashMap<ModuleName, HashSet<ModuleName>>,
+    reverse_deps: HashMap<ModuleName, HashSet<ModuleName>>,
 }
 
 impl Resolver {
@@ -35,28 +133,36 @@ impl Resolver {
         Self {
             loader,
             modules: HashMap::new(),
+            diagnostics: HashMap::new(),
+            forward_deps: HashMap::new(),
+            reverse_deps: HashMap::new(),
         }
     }
 
     pub fn add_module(&mut self, module_name: &ModuleName) -> Result<()> {
-        let mut in_progress = HashSet::new();
+        let mut in_progress = Vec::new();
         self.add_module_impl(&mut in_progress, module_name)
     }
 
     fn add_module_impl(
         &mut self,
-        in_progress: &mut HashSet<ModuleName>,
+        in_progress: &mut Vec<ModuleName>,
         module_name: &ModuleName,
     ) -> Result<()> {
         if self.modules.contains_key(module_name) {
             return Ok(());
         }
 
-        if in_progress.contains(module_name) {
-            return Err(ResolveError::CircularModules(module_name.clone()));
+        // `in_progress` is an ordered stack of the modules currently being resolved. If we
+        // re-enter one, the slice from its first occurrence to the end is the actual cycle;
+        // repeating the entry point makes the `A -> B -> C -> A` chain explicit.
+        if let Some(pos) = in_progress.iter().position(|m| m == module_name) {
+            let mut cycle = in_progress[pos..].to_vec();
+            cycle.push(module_name.clone());
+            return Err(ResolveError::CircularModules(cycle));
         }
 
-        in_progress.insert(module_name.clone());
+        in_progress.push(module_name.clone());
 
         let mut module0 = self
             .loader
@@ -66,8 +172,9 @@ impl Resolver {
         self.add_default_imports(&mut module0);
 
         let module_refs = find_module_refs(&module0);
+        self.record_deps(module_name, &module_refs);
         for m in &module_refs {
-            self.add_module(m)?;
+            self.add_module_impl(in_progress, m)?;
         }
 
         let type_params = HashSet::new();
@@ -78,14 +185,79 @@ impl Resolver {
             expanded_imports: &expanded_imports,
             type_params,
         };
-        let module1 = resolve_module(&mut ctx, &module0)?;
+        let (module1, mut diagnostics) = resolve_module(&mut ctx, &module0);
+        for imp in find_unused_imports(&module0, &expanded_imports) {
+            diagnostics.push(ResolveDiagnostic::warning(
+                module0.name.span,
+                format!("unused import `{}`", import_display(&imp)),
+            ));
+        }
         self.modules.insert(module_name.clone(), module1);
+        self.diagnostics.insert(module_name.clone(), diagnostics);
 
-        in_progress.remove(module_name);
+        in_progress.pop();
 
         Ok(())
     }
 
+    /// Diagnostics collected while resolving `module_name`, or an empty slice if the
+    /// module has not been resolved. Name-resolution problems are reported here rather
+    /// than aborting [`add_module`]; only loader and circular-import failures surface as
+    /// an `Err`.
+    pub fn get_diagnostics(&self, module_name: &ModuleName) -> &[ResolveDiagnostic] {
+        self.diagnostics
+            .get(module_name)
+            .map_or(&[], |ds| ds.as_slice())
+    }
+
+    /// Record the forward/reverse dependency edges for `module_name`, replacing any edges
+    /// from a previous resolution so stale reverse links don't accumulate across reloads.
+    fn record_deps(&mut self, module_name: &ModuleName, refs: &HashSet<ModuleName>) {
+        if let Some(old) = self.forward_deps.insert(module_name.clone(), refs.clone()) {
+            for dep in old {
+                if let Some(rev) = self.reverse_deps.get_mut(&dep) {
+                    rev.remove(module_name);
+                }
+            }
+        }
+        for dep in refs {
+            self.reverse_deps
+                .entry(dep.clone())
+                .or_default()
+                .insert(module_name.clone());
+        }
+    }
+
+    /// Drop `module_name` and every module that (directly or indirectly) imports it from the
+    /// resolved cache, so a subsequent [`add_module`] re-loads and re-resolves only that
+    /// invalidated frontier. Untouched modules keep their cached [`Module1`]. The dependency
+    /// edges of dropped modules are cleared too; they are rediscovered on the next resolve.
+    pub fn invalidate(&mut self, module_name: &ModuleName) {
+        let mut stale = Vec::new();
+        let mut queue = vec![module_name.clone()];
+        let mut seen = HashSet::new();
+        while let Some(m) = queue.pop() {
+            if !seen.insert(m.clone()) {
+                continue;
+            }
+            stale.push(m.clone());
+            if let Some(dependents) = self.reverse_deps.get(&m) {
+                queue.extend(dependents.iter().cloned());
+            }
+        }
+        for m in &stale {
+            self.modules.remove(m);
+            self.diagnostics.remove(m);
+            if let Some(old) = self.forward_deps.remove(m) {
+                for dep in old {
+                    if let Some(rev) = self.reverse_deps.get_mut(&dep) {
+                        rev.remove(m);
+                    }
+                }
+            }
+        }
+    }
+
     pub fn get_module_names(&self) -> Vec<ModuleName> {
         self.modules.keys().cloned().collect()
     }
@@ -102,182 +274,230 @@ impl Resolver {
     }
 
     pub fn add_default_imports(&self, module: &mut Module0) {
-        let default_imports = vec!["sys.annotations"];
-        for din in default_imports {
-            let di = adlast::Import::ModuleName(din.to_owned());
-            if module.name.value != din && !module.imports.contains(&di) {
+        for din in DEFAULT_IMPORTS {
+            let di = adlast::Import::ModuleName((*din).to_owned());
+            if module.name.value != *din && !module.imports.contains(&di) {
                 module.imports.push(di);
             }
         }
     }
 
-    pub fn get_expanded_imports(&self, module: &Module0) -> HashMap<String, adlast::ScopedName> {
-        let mut result = HashMap::new();
+    pub fn get_expanded_imports(&self, module: &Module0) -> HashMap<String, ImportResolution> {
+        // Explicit scoped imports are applied last and win deterministically: a
+        // wildcard can never shadow or make them ambiguous. Wildcard imports then
+        // accumulate their exported decls, and any local name claimed by two or more
+        // distinct scoped names (from different modules) becomes ambiguous rather than
+        // silently resolving to whichever was inserted last.
+        let mut explicit: HashMap<String, adlast::ScopedName> = HashMap::new();
+        let mut wildcard: HashMap<String, Vec<adlast::ScopedName>> = HashMap::new();
+
         for i in &module.imports {
             match i {
                 adlast::Import::ScopedName(sn) => {
-                    result.insert(sn.name.clone(), sn.clone());
+                    explicit.insert(sn.name.clone(), sn.clone());
                 }
                 adlast::Import::ModuleName(mn) => {
                     if let Some(m) = self.get_module(&mn) {
                         for decl_name in m.decls.keys() {
-                            result.insert(
-                                decl_name.clone(),
-                                adlast::ScopedName {
-                                    module_name: m.name.value.clone(),
-                                    name: decl_name.clone(),
-                                },
-                            );
+                            let sn = adlast::ScopedName {
+                                module_name: m.name.value.clone(),
+                                name: decl_name.clone(),
+                            };
+                            let candidates = wildcard.entry(decl_name.clone()).or_default();
+                            if !candidates.contains(&sn) {
+                                candidates.push(sn);
+                            }
                         }
                     }
                 }
             }
         }
+
+        let mut result = HashMap::new();
+        for (name, candidates) in wildcard {
+            if explicit.contains_key(&name) {
+                continue;
+            }
+            let resolution = if candidates.len() == 1 {
+                ImportResolution::Unique(candidates.into_iter().next().unwrap())
+            } else {
+                ImportResolution::Ambiguous(candidates)
+            };
+            result.insert(name, resolution);
+        }
+        for (name, sn) in explicit {
+            result.insert(name, ImportResolution::Unique(sn));
+        }
         result
     }
 }
 
-pub fn resolve_module(ctx: &mut ResolveCtx, module0: &Module0) -> Result<Module1> {
+/// How a local identifier resolves through the module's imports. A name brought in by two
+/// or more distinct wildcard imports is [`ImportResolution::Ambiguous`] unless an explicit
+/// scoped import disambiguates it.
+#[derive(Debug, Clone)]
+pub enum ImportResolution {
+    Unique(adlast::ScopedName),
+    Ambiguous(Vec<adlast::ScopedName>),
+}
+
+/// Resolve `module0`, keeping going past any failing decl/field/annotation so that every
+/// broken reference is reported in one pass. Returns a best-effort [`Module1`] (unresolved
+/// type references are left as-is; annotations whose name does not resolve to a declaration
+/// are dropped) together with a diagnostic per problem encountered.
+pub fn resolve_module(ctx: &mut ResolveCtx, module0: &Module0) -> (Module1, Vec<ResolveDiagnostic>) {
+    let mut diags = Vec::new();
     let decls1 = module0
         .decls
         .iter()
-        .map(|(n, decl0)| {
-            let decl1 = resolve_decl(ctx, &decl0)?;
-            Ok((n.clone(), decl1))
-        })
-        .collect::<Result<HashMap<_, _>>>()?;
-    let annotations1 = resolve_annotations(ctx, &module0.annotations)?;
+        .map(|(n, decl0)| (n.clone(), resolve_decl(ctx, decl0, &mut diags)))
+        .collect::<HashMap<_, _>>();
+    let annotations1 = resolve_annotations(ctx, &module0.annotations, module0.name.span, &mut diags);
     let module1 = adlast::Module::new(
         module0.name.clone(),
         module0.imports.clone(),
         decls1,
         annotations1,
     );
-    Ok(module1)
+    (module1, diags)
 }
 
 pub fn resolve_decl(
     ctx: &mut ResolveCtx,
     decl0: &adlast::Decl<TypeExpr0>,
-) -> Result<adlast::Decl<TypeExpr1>> {
+    diags: &mut Vec<ResolveDiagnostic>,
+) -> adlast::Decl<TypeExpr1> {
+    let span = decl0.name.span;
     let dtype = match &decl0.r#type {
-        adlast::DeclType::Struct(s) => resolve_struct(ctx, &s)?,
-        adlast::DeclType::Union(u) => resolve_union(ctx, &u)?,
-        adlast::DeclType::Type(t) => resolve_type_alias(ctx, &t)?,
-        adlast::DeclType::Newtype(n) => resolve_newtype(ctx, &n)?,
+        adlast::DeclType::Struct(s) => resolve_struct(ctx, s, diags),
+        adlast::DeclType::Union(u) => resolve_union(ctx, u, diags),
+        adlast::DeclType::Type(t) => resolve_type_alias(ctx, t, span, diags),
+        adlast::DeclType::Newtype(n) => resolve_newtype(ctx, n, span, diags),
     };
-    let annotations = resolve_annotations(ctx, &decl0.annotations)?;
-    let decl = adlast::Decl::new(
-        decl0.name.clone(),
-        decl0.version.clone(),
-        dtype,
-        annotations,
-    );
-    Ok(decl)
+    let annotations = resolve_annotations(ctx, &decl0.annotations, span, diags);
+    adlast::Decl::new(decl0.name.clone(), decl0.version.clone(), dtype, annotations)
 }
 
 pub fn resolve_struct(
     ctx0: &mut ResolveCtx,
     struct0: &adlast::Struct<TypeExpr0>,
-) -> Result<adlast::DeclType<TypeExpr1>> {
+    diags: &mut Vec<ResolveDiagnostic>,
+) -> adlast::DeclType<TypeExpr1> {
     let ctx = with_type_params(ctx0, &struct0.type_params);
-    let fields = resolve_fields(&ctx, &struct0.fields)?;
-    let struct1 = adlast::Struct::new(struct0.type_params.clone(), fields);
-    Ok(adlast::DeclType::Struct(struct1))
+    let fields = resolve_fields(&ctx, &struct0.fields, diags);
+    adlast::DeclType::Struct(adlast::Struct::new(struct0.type_params.clone(), fields))
 }
 
 pub fn resolve_union(
     ctx0: &mut ResolveCtx,
     union0: &adlast::Union<TypeExpr0>,
-) -> Result<adlast::DeclType<TypeExpr1>> {
+    diags: &mut Vec<ResolveDiagnostic>,
+) -> adlast::DeclType<TypeExpr1> {
     let ctx = with_type_params(ctx0, &union0.type_params);
-    let fields = resolve_fields(&ctx, &union0.fields)?;
-    let union1 = adlast::Union::new(union0.type_params.clone(), fields);
-    Ok(adlast::DeclType::Union(union1))
+    let fields = resolve_fields(&ctx, &union0.fields, diags);
+    adlast::DeclType::Union(adlast::Union::new(union0.type_params.clone(), fields))
 }
 
 pub fn resolve_type_alias(
     ctx0: &mut ResolveCtx,
     type0: &adlast::TypeDef<TypeExpr0>,
-) -> Result<adlast::DeclType<TypeExpr1>> {
+    span: Span,
+    diags: &mut Vec<ResolveDiagnostic>,
+) -> adlast::DeclType<TypeExpr1> {
     let ctx = with_type_params(ctx0, &type0.type_params);
-    let type_expr1 = resolve_type_expr(&ctx, &type0.type_expr)?;
-    let type1 = adlast::TypeDef::new(type0.type_params.clone(), type_expr1);
-    Ok(adlast::DeclType::Type(type1))
+    let type_expr1 = resolve_type_expr(&ctx, &type0.type_expr, span, diags);
+    adlast::DeclType::Type(adlast::TypeDef::new(type0.type_params.clone(), type_expr1))
 }
 
 pub fn resolve_newtype(
     ctx0: &mut ResolveCtx,
     newtype0: &adlast::NewType<TypeExpr0>,
-) -> Result<adlast::DeclType<TypeExpr1>> {
+    span: Span,
+    diags: &mut Vec<ResolveDiagnostic>,
+) -> adlast::DeclType<TypeExpr1> {
     let ctx = with_type_params(ctx0, &newtype0.type_params);
-    let type_expr1 = resolve_type_expr(&ctx, &newtype0.type_expr)?;
-    let newtype1 = adlast::NewType::new(
+    let type_expr1 = resolve_type_expr(&ctx, &newtype0.type_expr, span, diags);
+    adlast::DeclType::Newtype(adlast::NewType::new(
         newtype0.type_params.clone(),
         type_expr1,
         newtype0.default.clone(),
-    );
-    Ok(adlast::DeclType::Newtype(newtype1))
+    ))
 }
 
 pub fn resolve_fields(
     ctx: &ResolveCtx,
     fields0: &Vec<adlast::Field<TypeExpr0>>,
-) -> Result<Vec<adlast::Field<TypeExpr1>>> {
-    fields0
-        .iter()
-        .map(|f| resolve_field(ctx, f))
-        .collect::<Result<Vec<_>>>()
+    diags: &mut Vec<ResolveDiagnostic>,
+) -> Vec<adlast::Field<TypeExpr1>> {
+    fields0.iter().map(|f| resolve_field(ctx, f, diags)).collect()
 }
 
 pub fn resolve_field(
     ctx: &ResolveCtx,
     field0: &adlast::Field<TypeExpr0>,
-) -> Result<adlast::Field<TypeExpr1>> {
-    let field1 = adlast::Field::new(
+    diags: &mut Vec<ResolveDiagnostic>,
+) -> adlast::Field<TypeExpr1> {
+    let span = field0.name.span;
+    adlast::Field::new(
         field0.name.clone(),
         field0.serialized_name.clone(),
-        resolve_type_expr(ctx, &field0.type_expr)?,
+        resolve_type_expr(ctx, &field0.type_expr, span, diags),
         field0.default.clone(),
-        resolve_annotations(ctx, &field0.annotations)?,
-    );
-    Ok(field1)
+        resolve_annotations(ctx, &field0.annotations, span, diags),
+    )
 }
 
 pub fn resolve_annotations(
     ctx: &ResolveCtx,
     annotations0: &adlast::Annotations,
-) -> Result<adlast::Annotations> {
-    let hm1 = annotations0
-        .0
-        .iter()
-        .map(|(sn0, jv)| {
-            let tr1 = ctx.resolve_type_ref(sn0)?;
-            if let TypeRef::ScopedName(sn1) = tr1 {
-                Ok((sn1, jv.clone()))
-            } else {
-                Err(ResolveError::NoDeclForAnnotation)
+    span: Span,
+    diags: &mut Vec<ResolveDiagnostic>,
+) -> adlast::Annotations {
+    let mut hm1 = HashMap::new();
+    for (sn0, jv) in annotations0.0.iter() {
+        match ctx.resolve_type_ref(sn0) {
+            Ok(TypeRef::ScopedName(sn1)) => {
+                hm1.insert(sn1, jv.clone());
             }
-        })
-        .collect::<Result<HashMap<_, _>>>()?;
-    Ok(Map(hm1))
+            Ok(_) => diags.push(ResolveDiagnostic::error(span, &ResolveError::NoDeclForAnnotation)),
+            Err(e) => diags.push(ResolveDiagnostic::error(span, &e)),
+        }
+    }
+    Map(hm1)
 }
 
-pub fn resolve_type_expr(ctx: &ResolveCtx, typeexpr0: &TypeExpr0) -> Result<TypeExpr1> {
-    let type_ref = ctx.resolve_type_ref(&typeexpr0.type_ref)?;
+/// Resolve a type expression, recording a diagnostic for each unresolved reference and
+/// leaving the offending name in place so resolution continues.
+///
+/// `ScopedName`/`TypeExpr0` carry no span in this AST, so every diagnostic — including those
+/// from nested type parameters — is anchored at `span`, the enclosing decl/field name. That
+/// is the finest location currently available; distinguishing `Foo<Bad1, Bad2>` at the
+/// reference level would require the parser to thread a span onto each `ScopedName`.
+pub fn resolve_type_expr(
+    ctx: &ResolveCtx,
+    typeexpr0: &TypeExpr0,
+    span: Span,
+    diags: &mut Vec<ResolveDiagnostic>,
+) -> TypeExpr1 {
+    let type_ref = match ctx.resolve_type_ref(&typeexpr0.type_ref) {
+        Ok(type_ref) => type_ref,
+        Err(e) => {
+            diags.push(ResolveDiagnostic::error(span, &e));
+            TypeRef::ScopedName(typeexpr0.type_ref.clone())
+        }
+    };
     let parameters = typeexpr0
         .parameters
         .iter()
-        .map(|p| resolve_type_expr(ctx, p))
-        .collect::<Result<Vec<_>>>()?;
-    let type_expr = adlast::TypeExpr::new(type_ref, parameters);
-    Ok(type_expr)
+        .map(|p| resolve_type_expr(ctx, p, span, diags))
+        .collect::<Vec<_>>();
+    adlast::TypeExpr::new(type_ref, parameters)
 }
 
 pub struct ResolveCtx<'a> {
     resolver: &'a mut Resolver,
     module0: &'a Module0,
-    expanded_imports: &'a HashMap<adlast::Ident, adlast::ScopedName>,
+    expanded_imports: &'a HashMap<adlast::Ident, ImportResolution>,
     type_params: HashSet<String>,
 }
 
@@ -298,15 +518,43 @@ impl<'a> ResolveCtx<'a> {
             if self.module0.decls.contains_key(name) {
                 return Ok(TypeRef::LocalName(name.clone()));
             }
-            if let Some(scoped_name) = self.expanded_imports.get(name) {
-                return Ok(TypeRef::ScopedName(scoped_name.clone()));
+            if let Some(resolution) = self.expanded_imports.get(name) {
+                match resolution {
+                    ImportResolution::Unique(scoped_name) => {
+                        return Ok(TypeRef::ScopedName(scoped_name.clone()))
+                    }
+                    ImportResolution::Ambiguous(candidates) => {
+                        return Err(ResolveError::AmbiguousName {
+                            name: name.clone(),
+                            candidates: candidates.clone(),
+                        })
+                    }
+                }
             }
-            Err(ResolveError::LocalNotFound(name.clone()))
+            let candidates = self
+                .module0
+                .decls
+                .keys()
+                .chain(self.expanded_imports.keys())
+                .chain(self.type_params.iter())
+                .map(|s| s.as_str())
+                .chain(PRIMITIVE_NAMES.iter().copied());
+            Err(ResolveError::LocalNotFound {
+                name: name.clone(),
+                suggestion: closest_match(name, candidates),
+            })
         } else {
             match self.find_module(&scoped_name0.module_name)? {
                 None => return Err(ResolveError::ModuleNotFound),
                 Some(module1) => match module1.decls.get(&scoped_name0.name) {
-                    None => return Err(ResolveError::DeclNotFound),
+                    None => {
+                        let suggestion =
+                            closest_match(&scoped_name0.name, module1.decls.keys().map(|s| s.as_str()));
+                        Err(ResolveError::DeclNotFound {
+                            name: scoped_name0.name.clone(),
+                            suggestion,
+                        })
+                    }
                     Some(_) => return Ok(TypeRef::ScopedName(scoped_name0.clone())),
                 },
             }
@@ -314,6 +562,52 @@ impl<'a> ResolveCtx<'a> {
     }
 }
 
+/// Primitive spellings offered as `did you mean` candidates, mirroring `prim_from_str`.
+const PRIMITIVE_NAMES: &[&str] = &[
+    "Void", "Bool", "Int8", "Int16", "Int32", "Int64", "Word8", "Word16", "Word32", "Word64",
+    "Float", "Double", "Bytes", "String", "Vector", "StringMap", "Nullable", "TypeToken",
+];
+
+/// Return the candidate closest to `q` by Levenshtein distance, accepting it only when the
+/// distance is within `max(1, |q| / 3)`. Candidates whose length differs from `|q|` by more
+/// than the threshold are pruned before the (more expensive) distance computation.
+fn closest_match<'a>(q: &str, candidates: impl Iterator<Item = &'a str>) -> Option<String> {
+    let threshold = std::cmp::max(1, q.chars().count() / 3);
+    let qlen = q.chars().count();
+    let mut best: Option<(usize, &str)> = None;
+    for cand in candidates {
+        let clen = cand.chars().count();
+        if clen.abs_diff(qlen) > threshold {
+            continue;
+        }
+        let d = edit_distance(q, cand);
+        if d <= threshold && best.map_or(true, |(bd, _)| d < bd) {
+            best = Some((d, cand));
+        }
+    }
+    best.map(|(_, cand)| cand.to_owned())
+}
+
+/// Classic two-row dynamic-programming Levenshtein edit distance.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0usize; b.len() + 1];
+    for i in 1..=a.len() {
+        curr[0] = i;
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            curr[j] = std::cmp::min(
+                std::cmp::min(prev[j] + 1, curr[j - 1] + 1),
+                prev[j - 1] + cost,
+            );
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
 fn with_type_params<'a>(
     ctx0: &'a mut ResolveCtx,
     type_params: &'a Vec<adlast::Ident>,
@@ -360,6 +654,73 @@ fn find_module_refs(module: &Module0) -> HashSet<ModuleName> {
     ac.refs
 }
 
+/// Report author-written imports that contribute no referenced declaration. A bare reference
+/// is credited to the import(s) that `expanded_imports` maps it to (every candidate, for an
+/// ambiguous name). Unlike `resolve_type_ref`, type parameters, primitives and local decls
+/// are not checked first, so a bare name that one of those shadows still counts as a use of
+/// an import exporting the same name. A wildcard `Import::ModuleName` is flagged only when
+/// none of its exported decls are used. Resolver-injected default imports (see
+/// [`DEFAULT_IMPORTS`]) are never flagged, since the author never wrote them.
+///
+/// `adlast::Import` carries no source span in this AST, so callers anchor the resulting
+/// diagnostics at the module header rather than the individual import.
+pub fn find_unused_imports(
+    module0: &Module0,
+    expanded_imports: &HashMap<String, ImportResolution>,
+) -> Vec<adlast::Import> {
+    struct C<'a> {
+        expanded: &'a HashMap<String, ImportResolution>,
+        used: HashSet<adlast::ScopedName>,
+    }
+    impl<'a> AstConsumer<adlast::ScopedName> for C<'a> {
+        fn consume_typeref(&mut self, sn: adlast::ScopedName) {
+            self.consume_scoped_name(sn)
+        }
+        fn consume_scoped_name(&mut self, sn: adlast::ScopedName) {
+            if sn.module_name.is_empty() {
+                match self.expanded.get(&sn.name) {
+                    Some(ImportResolution::Unique(r)) => {
+                        self.used.insert(r.clone());
+                    }
+                    Some(ImportResolution::Ambiguous(cands)) => {
+                        for c in cands {
+                            self.used.insert(c.clone());
+                        }
+                    }
+                    None => {}
+                }
+            } else {
+                self.used.insert(sn);
+            }
+        }
+    }
+    let mut ac = C {
+        expanded: expanded_imports,
+        used: HashSet::new(),
+    };
+    consume_module(module0, &mut ac);
+
+    module0
+        .imports
+        .iter()
+        .filter(|imp| match imp {
+            adlast::Import::ScopedName(sn) => !ac.used.contains(sn),
+            adlast::Import::ModuleName(mn) => {
+                !DEFAULT_IMPORTS.contains(&mn.as_str())
+                    && !ac.used.iter().any(|u| &u.module_name == mn)
+            }
+        })
+        .cloned()
+        .collect()
+}
+
+fn import_display(import: &adlast::Import) -> String {
+    match import {
+        adlast::Import::ScopedName(sn) => format!("{}.{}", sn.module_name, sn.name),
+        adlast::Import::ModuleName(mn) => format!("{}.*", mn),
+    }
+}
+
 pub fn consume_module<T: Clone>(
     module: &adlast::Module<adlast::TypeExpr<T>>,
     ac: &mut dyn AstConsumer<T>,
@@ -417,3 +778,94 @@ pub trait AstConsumer<TR> {
     fn consume_typeref(&mut self, t: TR) -> ();
     fn consume_scoped_name(&mut self, sn: adlast::ScopedName) -> ();
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    use nom_locate::LocatedSpan;
+
+    use super::{ModuleName, Resolver};
+    use crate::parser::raw_module;
+    use crate::processing::annotations::apply_explicit_annotations;
+    use crate::processing::loader::AdlLoader;
+    use crate::processing::Module0;
+
+    /// An `AdlLoader` backed by in-memory ADL sources that records how many times each
+    /// module was loaded, so tests can assert exactly which modules get re-resolved.
+    struct CountingLoader {
+        sources: HashMap<ModuleName, &'static str>,
+        loads: Rc<RefCell<HashMap<ModuleName, u32>>>,
+    }
+
+    impl AdlLoader for CountingLoader {
+        fn load(&mut self, module_name: &ModuleName) -> Result<Option<Module0>, anyhow::Error> {
+            *self.loads.borrow_mut().entry(module_name.clone()).or_default() += 1;
+            match self.sources.get(module_name) {
+                Some(src) => {
+                    let rm = raw_module(LocatedSpan::new(*src)).unwrap().1;
+                    Ok(Some(apply_explicit_annotations(rm).unwrap()))
+                }
+                None => Ok(None),
+            }
+        }
+    }
+
+    fn mn(s: &str) -> ModuleName {
+        s.to_owned()
+    }
+
+    #[test]
+    fn invalidate_reresolves_only_the_dependent_frontier() {
+        let loads = Rc::new(RefCell::new(HashMap::new()));
+        let mut sources = HashMap::new();
+        sources.insert(mn("sys.annotations"), "module sys.annotations {}");
+        sources.insert(mn("test.c"), "module test.c { struct C { Int32 x; }; }");
+        sources.insert(
+            mn("test.b"),
+            "module test.b { import test.c.C; struct B { C c; }; }",
+        );
+        sources.insert(
+            mn("test.a"),
+            "module test.a { import test.b.B; struct A { B b; }; }",
+        );
+        sources.insert(
+            mn("test.unrelated"),
+            "module test.unrelated { struct U { Int32 y; }; }",
+        );
+
+        let loader = CountingLoader {
+            sources,
+            loads: loads.clone(),
+        };
+        let mut resolver = Resolver::new(Box::new(loader));
+
+        resolver.add_module(&mn("test.a")).unwrap();
+        resolver.add_module(&mn("test.unrelated")).unwrap();
+
+        // A -> B -> C were each loaded exactly once while resolving the closure of A.
+        assert_eq!(loads.borrow().get("test.a"), Some(&1));
+        assert_eq!(loads.borrow().get("test.b"), Some(&1));
+        assert_eq!(loads.borrow().get("test.c"), Some(&1));
+
+        resolver.invalidate(&mn("test.c"));
+
+        // C and everything that transitively imports it is dropped; unrelated stays cached.
+        assert!(resolver.get_module(&mn("test.c")).is_none());
+        assert!(resolver.get_module(&mn("test.b")).is_none());
+        assert!(resolver.get_module(&mn("test.a")).is_none());
+        assert!(resolver.get_module(&mn("test.unrelated")).is_some());
+
+        resolver.add_module(&mn("test.a")).unwrap();
+
+        // Re-adding A re-ran the loader for the invalidated frontier (A, B, C) but not for
+        // the unrelated module, nor for the still-cached default-imported sys.annotations.
+        assert_eq!(loads.borrow().get("test.a"), Some(&2));
+        assert_eq!(loads.borrow().get("test.b"), Some(&2));
+        assert_eq!(loads.borrow().get("test.c"), Some(&2));
+        assert_eq!(loads.borrow().get("test.unrelated"), Some(&1));
+        assert_eq!(loads.borrow().get("sys.annotations"), Some(&1));
+    }
+}